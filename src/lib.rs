@@ -2,14 +2,15 @@ pub mod cipher {
     use rand::{Rng, thread_rng};
     use rand::distributions::Uniform;
     use std::collections::HashMap;
+    use std::fmt;
     /// # Ciphers
     /// This crate is implementation of two main traditional ciphers.
     /// ## Shift Cipher
-    ///   -  In this cipher, the character are shifted by the key. 
+    ///   -  In this cipher, the character are shifted by the key.
     ///     For example, if key is 15 and original-text is "hello" then
     ///     cipher-text is "wtaad" (i.e. h + 15 = w). It is also known as rot cipher.
     ///     We implemented here the Shift Cipher for English alphabets. It is also
-    ///     known as Caesar Cipher on the name of Julius Caesar who used to use it 
+    ///     known as Caesar Cipher on the name of Julius Caesar who used to use it
     ///     to communicate with his officers.
     /// ## Transpositional Cipher
     ///   -  In this cipher, the symbols in block of symbols are reordered or permutated.
@@ -52,10 +53,516 @@ pub mod cipher {
     ///     println!("Key: {:?}", manchine);
     ///     println!("Cipher: {:?}", cipher);
     /// ```
+    /// ## Vigenère Cipher
+    ///   -  In this cipher, each character is shifted by the corresponding letter of a
+    ///     repeating keyword, cycling the keyword over the alphabetic characters of the
+    ///     text and leaving non-alphabetic characters untouched. For example, with keyword
+    ///     "key" and original-text "hello" the cipher-text is "rijvs". It is a polyalphabetic
+    ///     generalisation of the Shift Cipher, and `break_vigenere` can recover the keyword
+    ///     from cipher-text alone using index-of-coincidence key-length estimation followed
+    ///     by per-column chi-squared frequency analysis.
+    /// ### Vigenère Cipher
+
+    /// ```
+    ///     use classical_ciphers::cipher::Ciphers;
+    ///     //Data
+    ///     let manchine = Ciphers::VigenereCipher(String::from("key")); //creating a cipher key data
+    ///     let message = String::from("hello");
+    ///     //Running
+    ///     let cipher = manchine.clone().encrypt(message); //encrypting the message
+    ///     let message = manchine.clone().decrypt(cipher.clone()); //decrypting the message
+    ///     //Output
+    ///     println!("Message: {:?}", message);
+    ///     println!("Key: {:?}", manchine);
+    ///     println!("Cipher: {:?}", cipher);
+    /// ```
     #[derive(Debug, Clone)]
     pub enum Ciphers {
-        ShiftCipher(u8), 
-        TransCipher(Vec<usize>), 
+        ShiftCipher(u8),
+        TransCipher(Vec<usize>),
+        VigenereCipher(String),
+        XorCipher(Vec<u8>),
+        /// Transposition with PKCS#7-style padding; see `Ciphers::trans_with_padding`.
+        TransCipherPadded(Vec<usize>, char),
+    }
+
+    /// Error returned by the fallible `Ciphers::try_encrypt`/`try_decrypt`.
+    #[derive(Debug, PartialEq, Eq)]
+    pub enum CipherError {
+        InvalidPadding,
+        /// The cipher produced bytes that aren't valid UTF-8 (currently only
+        /// possible for `XorCipher`, which operates on raw bytes).
+        InvalidUtf8,
+        /// The key was empty, which would make `XorCipher` divide by zero
+        /// while indexing into it.
+        EmptyKey,
+    }
+
+    impl fmt::Display for CipherError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Self::InvalidPadding => write!(f, "cipher-text padding is invalid"),
+                Self::InvalidUtf8 => write!(f, "cipher output is not valid UTF-8"),
+                Self::EmptyKey => write!(f, "key must not be empty"),
+            }
+        }
+    }
+
+    impl std::error::Error for CipherError {}
+
+    /// Canonical English letter frequencies (a-z), used to score candidate
+    /// decryptions when breaking a cipher without knowing its key.
+    const ENGLISH_FREQ: [f64; 26] = [
+        0.08167, 0.01492, 0.02782, 0.04253, 0.12702, 0.02228, 0.02015, 0.06094,
+        0.06966, 0.00153, 0.00772, 0.04025, 0.02406, 0.06749, 0.07507, 0.01929,
+        0.00095, 0.05987, 0.06327, 0.09056, 0.02758, 0.00978, 0.02360, 0.00150,
+        0.01974, 0.00074,
+    ];
+
+    /// A cipher that can encrypt and decrypt text given a key of its own
+    /// associated type. `Caesar`, `Transposition` and `Vigenere` implement
+    /// this so generic code can be written over any of them; `Ciphers` is a
+    /// separate, enum-based convenience wrapper for callers who'd rather
+    /// store the key and algorithm together.
+    pub trait Cipher {
+        type Key;
+        fn new(key: Self::Key) -> Self;
+        fn encrypt(&self, text: &str) -> String;
+        fn decrypt(&self, text: &str) -> String;
+    }
+
+    /// The Shift (Caesar) Cipher. `new` panics unless `key` is in `1..=25`,
+    /// since 0 and 26 would leave the alphabet unchanged.
+    pub struct Caesar {
+        key: u8,
+    }
+
+    impl Cipher for Caesar {
+        type Key = u8;
+
+        fn new(key: u8) -> Self {
+            assert!((1..=25).contains(&key), "Caesar key must be in 1..=25, got {key}");
+            Caesar { key }
+        }
+
+        fn encrypt(&self, text: &str) -> String {
+            let mut chars: Vec<char> = text.chars().collect();
+            rot_vec_up(self.key, &mut chars);
+            chars.into_iter().collect()
+        }
+
+        fn decrypt(&self, text: &str) -> String {
+            let mut chars: Vec<char> = text.chars().collect();
+            rot_vec_down(self.key, &mut chars);
+            chars.into_iter().collect()
+        }
+    }
+
+    /// The Transpositional Cipher. `new` panics unless `key` is a
+    /// permutation of `0..key.len()`.
+    pub struct Transposition {
+        key: Vec<usize>,
+    }
+
+    impl Cipher for Transposition {
+        type Key = Vec<usize>;
+
+        fn new(key: Vec<usize>) -> Self {
+            let mut seen = vec![false; key.len()];
+            for &k in &key {
+                assert!(k < key.len(), "Transposition key must be a permutation of 0..key.len()");
+                assert!(!seen[k], "Transposition key must be a permutation of 0..key.len()");
+                seen[k] = true;
+            }
+            Transposition { key }
+        }
+
+        fn encrypt(&self, text: &str) -> String {
+            let mut chars: Vec<char> = text.chars().collect();
+            encrypt_trans_vec(&self.key, &mut chars);
+            chars.into_iter().collect()
+        }
+
+        fn decrypt(&self, text: &str) -> String {
+            let mut chars: Vec<char> = text.chars().collect();
+            decrypt_trans_vec(&self.key, &mut chars);
+            chars.into_iter().collect()
+        }
+    }
+
+    /// The Vigenère Cipher. `new` panics unless `key` is a non-empty,
+    /// purely alphabetic keyword.
+    pub struct Vigenere {
+        key: String,
+    }
+
+    impl Cipher for Vigenere {
+        type Key = String;
+
+        fn new(key: String) -> Self {
+            assert!(
+                !key.is_empty() && key.bytes().all(|b| b.is_ascii_alphabetic()),
+                "Vigenere key must be a non-empty alphabetic keyword"
+            );
+            Vigenere { key }
+        }
+
+        fn encrypt(&self, text: &str) -> String {
+            let mut chars: Vec<char> = text.chars().collect();
+            rot_vec_up_keyed(&self.key, &mut chars);
+            chars.into_iter().collect()
+        }
+
+        fn decrypt(&self, text: &str) -> String {
+            let mut chars: Vec<char> = text.chars().collect();
+            rot_vec_down_keyed(&self.key, &mut chars);
+            chars.into_iter().collect()
+        }
+    }
+
+    fn rot_vec_up(key: u8, org: &mut [char]) {
+        for c in org.iter_mut() {
+            *c = rot_char_up(key, *c);
+        }
+    }
+
+    fn rot_vec_down(key: u8, org: &mut [char]) {
+        for c in org.iter_mut() {
+            *c = rot_char_down(key, *c);
+        }
+    }
+
+    /// Rotates an ASCII letter up by `key`, leaving any other character
+    /// (including non-ASCII text) untouched.
+    fn rot_char_up(key: u8, c: char) -> char {
+        if c.is_ascii_uppercase() || c.is_ascii_lowercase() {
+            rot_up(key, c as u8) as char
+        } else {
+            c
+        }
+    }
+
+    /// Rotates an ASCII letter down by `key`, leaving any other character
+    /// (including non-ASCII text) untouched.
+    fn rot_char_down(key: u8, c: char) -> char {
+        if c.is_ascii_uppercase() || c.is_ascii_lowercase() {
+            rot_down(key, c as u8) as char
+        } else {
+            c
+        }
+    }
+
+    fn rot_up(key: u8, x: u8) -> u8 {
+
+        let rot_wrap_up = |b: u8| key - (b - x + 1);
+        match x {
+            x @ b'A'..=b'Z' if key + x > b'Z' => rot_up(rot_wrap_up(b'Z'), b'A'), // For wrap around behaviour
+            x @ b'a'..=b'z' if key + x > b'z' => rot_up(rot_wrap_up(b'z'), b'a'),
+            _ => x + key
+        }
+    }
+
+    fn rot_down(key: u8, x: u8) -> u8 {
+        // HELLO => WTAAD
+        let rot_wrap_down = |b: u8| key - (x - b + 1);
+        match x {
+            x @ b'A'..=b'Z' if x - key < b'A' => rot_down(rot_wrap_down(b'A'), b'Z'), // For wrap around behaviour
+            x @ b'a'..=b'z' if x - key < b'a' => rot_down(rot_wrap_down(b'a'), b'z'),
+            _ => x - key
+        }
+    }
+
+    fn rot_vec_up_keyed(key: &str, org: &mut [char]) {
+        let key: Vec<u8> = key.bytes().collect();
+        if key.is_empty() {
+            return;
+        }
+        let mut k = 0;
+        for c in org.iter_mut() {
+            if c.is_ascii_uppercase() || c.is_ascii_lowercase() {
+                let shift = key_shift(key[k % key.len()]);
+                *c = rot_up(shift, *c as u8) as char;
+                k += 1;
+            }
+        }
+    }
+
+    fn rot_vec_down_keyed(key: &str, org: &mut [char]) {
+        let key: Vec<u8> = key.bytes().collect();
+        if key.is_empty() {
+            return;
+        }
+        let mut k = 0;
+        for c in org.iter_mut() {
+            if c.is_ascii_uppercase() || c.is_ascii_lowercase() {
+                let shift = key_shift(key[k % key.len()]);
+                *c = rot_down(shift, *c as u8) as char;
+                k += 1;
+            }
+        }
+    }
+
+    /// Maps a keyword letter to its 0..26 shift amount, A/a being no shift.
+    fn key_shift(k: u8) -> u8 {
+        match k {
+            b'A'..=b'Z' => k - b'A',
+            b'a'..=b'z' => k - b'a',
+            _ => 0
+        }
+    }
+
+    fn to_upper(b: u8) -> u8 {
+        if b.is_ascii_lowercase() { b - 32 } else { b }
+    }
+
+    /// Computes the index of coincidence of a run of uppercase letters:
+    /// the probability that two letters drawn at random from it match.
+    fn index_of_coincidence(column: &[u8]) -> f64 {
+        let mut counts = [0u32; 26];
+        for &b in column {
+            counts[(to_upper(b) - b'A') as usize] += 1;
+        }
+        let n = column.len() as f64;
+        if n < 2.0 {
+            return 0.0;
+        }
+        let numerator: f64 = counts.iter()
+            .map(|&c| f64::from(c) * (f64::from(c) - 1.0))
+            .sum();
+        numerator / (n * (n - 1.0))
+    }
+
+    /// Chi-squared distance between observed letter counts and the
+    /// expected counts implied by `ENGLISH_FREQ` for `total` letters.
+    fn chi_squared(counts: &[u32; 26], total: f64) -> f64 {
+        ENGLISH_FREQ.iter().enumerate()
+            .map(|(i, &freq)| {
+                let expected = freq * total;
+                let observed = f64::from(counts[i]);
+                (observed - expected).powi(2) / expected
+            })
+            .sum()
+    }
+
+    /// Treats `letters` as Shift-Cipher text and returns the shift that
+    /// minimises chi-squared distance from English letter frequencies.
+    fn best_caesar_shift(letters: &[u8]) -> u8 {
+        let total = letters.len() as f64;
+        let mut best_shift = 0u8;
+        let mut best_score = f64::MAX;
+        for shift in 0..26u8 {
+            let mut counts = [0u32; 26];
+            for &b in letters {
+                let decrypted = rot_down(shift, to_upper(b));
+                counts[(decrypted - b'A') as usize] += 1;
+            }
+            let score = chi_squared(&counts, total);
+            if score < best_score {
+                best_score = score;
+                best_shift = shift;
+            }
+        }
+        best_shift
+    }
+
+    /// Index of coincidence at or above which a sub-sequence counts as
+    /// English-like; English prose averages about 0.067, so sub-sequences
+    /// split at the true key length should land at or near that value.
+    const ENGLISH_IC_THRESHOLD: f64 = 0.06;
+
+    /// Estimates the Vigenère keyword length by trying candidate lengths
+    /// `1..=20` and returning the smallest one whose sub-sequences have an
+    /// average index of coincidence at or above `ENGLISH_IC_THRESHOLD`.
+    /// Picking the raw maximum instead latches onto harmonics of the true
+    /// length (e.g. 10 instead of 5), so the smallest length that already
+    /// looks English-like is preferred. Falls back to the length with the
+    /// highest average IC if none clears the threshold.
+    fn estimate_vigenere_key_length(letters: &[u8]) -> usize {
+        let max_len = 20.min(letters.len().max(1));
+        let mut best_len = 1;
+        let mut best_ic = f64::MIN;
+        for len in 1..=max_len {
+            let mut total_ic = 0.0;
+            for col in 0..len {
+                let column: Vec<u8> = letters.iter()
+                    .skip(col)
+                    .step_by(len)
+                    .copied()
+                    .collect();
+                total_ic += index_of_coincidence(&column);
+            }
+            let avg_ic = total_ic / len as f64;
+            if avg_ic >= ENGLISH_IC_THRESHOLD {
+                return len;
+            }
+            if avg_ic > best_ic {
+                best_ic = avg_ic;
+                best_len = len;
+            }
+        }
+        best_len
+    }
+
+    fn xor_vec(key: &[u8], org: &mut [u8]) {
+        for (i, b) in org.iter_mut().enumerate() {
+            *b ^= key[i % key.len()];
+        }
+    }
+
+    /// Number of differing bits between two equal-length byte slices.
+    fn hamming_distance(a: &[u8], b: &[u8]) -> u32 {
+        a.iter().zip(b).map(|(&x, &y)| (x ^ y).count_ones()).sum()
+    }
+
+    /// A candidate keysize is accepted over the best-scoring one found so
+    /// far if its score is within this fraction of the best: true multiples
+    /// of the real keysize all score similarly low (their key bytes cancel
+    /// out of the comparison), so scoring noise can make a larger harmonic
+    /// look marginally better than the real keysize. Preferring the
+    /// smallest "good enough" candidate avoids that.
+    const XOR_KEYSIZE_SCORE_TOLERANCE: f64 = 1.1;
+
+    /// Estimates the repeating-key XOR keysize in `2..=40` by scoring every
+    /// candidate on the average normalized Hamming distance (Hamming
+    /// distance divided by the keysize) across every pair of its blocks
+    /// spanning the whole ciphertext, then returning the smallest keysize
+    /// whose score is within `XOR_KEYSIZE_SCORE_TOLERANCE` of the best score
+    /// found. True keysize multiples all score close to the minimum, so
+    /// taking the smallest of them picks the real keysize instead of a
+    /// harmonic.
+    fn estimate_xor_keysize(cipher: &[u8]) -> usize {
+        let max_size = 40.min(cipher.len() / 2).max(2);
+        let mut scores = Vec::with_capacity(max_size - 1);
+        for size in 2..=max_size {
+            let blocks: Vec<&[u8]> = cipher.chunks_exact(size).collect();
+            if blocks.len() < 2 {
+                continue;
+            }
+            let mut total = 0.0;
+            let mut pairs = 0u32;
+            for i in 0..blocks.len() {
+                for j in (i + 1)..blocks.len() {
+                    total += f64::from(hamming_distance(blocks[i], blocks[j])) / size as f64;
+                    pairs += 1;
+                }
+            }
+            scores.push((size, total / f64::from(pairs)));
+        }
+        let best_score = scores.iter()
+            .map(|&(_, score)| score)
+            .fold(f64::MAX, f64::min);
+        let threshold = best_score * XOR_KEYSIZE_SCORE_TOLERANCE;
+        scores.iter()
+            .filter(|&&(_, score)| score <= threshold)
+            .map(|&(size, _)| size)
+            .min()
+            .unwrap_or(2)
+    }
+
+    /// Scores a decrypted byte by how plausible it is in English text:
+    /// letters get their relative frequency, space is weighted highly, and
+    /// non-printable bytes are penalised.
+    fn byte_score(b: u8) -> f64 {
+        if b.is_ascii_alphabetic() {
+            ENGLISH_FREQ[(to_upper(b) - b'A') as usize]
+        } else if b == b' ' {
+            0.13
+        } else if b.is_ascii_graphic() || b == b'\n' || b == b'\t' {
+            0.0
+        } else {
+            -1.0
+        }
+    }
+
+    /// Treats `column` as single-byte XOR cipher-text and returns the key
+    /// byte whose decryption scores best against English letter/space
+    /// frequency.
+    fn best_single_byte_xor_key(column: &[u8]) -> u8 {
+        let mut best_byte = 0u8;
+        let mut best_score = f64::MIN;
+        for candidate in 0..=255u8 {
+            let score: f64 = column.iter().map(|&b| byte_score(b ^ candidate)).sum();
+            if score > best_score {
+                best_score = score;
+                best_byte = candidate;
+            }
+        }
+        best_byte
+    }
+
+    fn encrypt_trans_vec(key: &[usize], org: &mut [char]) {
+        let key_size = key.len();
+        if key_size == 0 {
+            return;
+        }
+        let mut i = 0;
+        while i + key_size <= org.len() {
+        // print!("{} ", quo);
+            encrypt_with_accord(&mut org[i..key_size + i], key);
+            i += key_size;
+        }
+    }
+
+    fn decrypt_trans_vec(key: &[usize], org: &mut [char]) {
+        let key_size = key.len();
+        if key_size == 0 {
+            return;
+        }
+        let mut i = 0;
+        while i + key_size <= org.len() {
+        // print!("{} ", quo);
+            decrypt_with_accord(&mut org[i..key_size + i], key);
+            i += key_size;
+        }
+    }
+
+    /// Pads `chars` up to a multiple of `block_size` with copies of
+    /// `pad_char`, always adding a full block when already aligned. A zero
+    /// `block_size` has no well-defined multiple, so `chars` is left
+    /// untouched; `pkcs7_unpad` will then reject the (unpadded) result.
+    fn pkcs7_pad(chars: &mut Vec<char>, block_size: usize, pad_char: char) {
+        if block_size == 0 {
+            return;
+        }
+        let remainder = chars.len() % block_size;
+        let pad_len = if remainder == 0 { block_size } else { block_size - remainder };
+        chars.extend(std::iter::repeat_n(pad_char, pad_len));
+    }
+
+    /// Strips PKCS#7-style padding added by `pkcs7_pad`, verifying the
+    /// trailing run of `pad_char` is a consistent length before removing it.
+    fn pkcs7_unpad(chars: &mut Vec<char>, block_size: usize, pad_char: char) -> Result<(), CipherError> {
+        if chars.is_empty() || !chars.len().is_multiple_of(block_size) {
+            return Err(CipherError::InvalidPadding);
+        }
+        let pad_len = chars.iter().rev().take_while(|&&c| c == pad_char).count();
+        if pad_len == 0 || pad_len > block_size {
+            return Err(CipherError::InvalidPadding);
+        }
+        chars.truncate(chars.len() - pad_len);
+        Ok(())
+    }
+
+    fn encrypt_with_accord<T: Copy>(v: &mut [T], acc: &[usize]) {
+        let mut num: HashMap<usize, T> = HashMap::new();
+        for i in 0..v.len() {
+            num.insert(i, v[i]);
+            v[i] = match num.contains_key(&(acc[i])) {
+                true    => num.remove(&(acc[i])).unwrap(),
+                false   => v[acc[i]]
+            }
+        }
+    }
+
+    fn decrypt_with_accord<T: Copy>(v: &mut [T], acc: &[usize]) {
+        let mut num: HashMap<usize, T> = HashMap::new();
+        for i in 0..v.len() {
+            num.insert(acc[acc[i]] as usize, v[acc[i]]);
+            v[acc[i]] = match num.contains_key(&(acc[i])) {
+                true    => num.remove(&(acc[i])).unwrap(),
+                false   => v[i]
+            }
+        }
     }
 
     impl Ciphers {
@@ -63,160 +570,217 @@ pub mod cipher {
         pub fn key(&self) -> u8 {
             match *self {
                 Self::ShiftCipher(ref key) => *key,
-                Self::TransCipher(ref key) => key.len() as u8
+                Self::TransCipher(ref key) => key.len() as u8,
+                Self::VigenereCipher(ref key) => key.len() as u8,
+                Self::XorCipher(ref key) => key.len() as u8,
+                Self::TransCipherPadded(ref key, _) => key.len() as u8
             }
         }
         /// Encrypts the data
         pub fn encrypt(self, original_text: String) -> String {
             match self {
-                Self::ShiftCipher(key) => 
+                Self::ShiftCipher(key) =>
                     Self::shift_encrypt(key % 26, original_text),
-                Self::TransCipher(key) => 
-                    Self::trans_encrypt(key,original_text)
+                Self::TransCipher(key) =>
+                    Self::trans_encrypt(&key, original_text),
+                Self::VigenereCipher(key) =>
+                    Self::vigenere_encrypt(&key, original_text),
+                Self::XorCipher(key) =>
+                    Self::xor_encrypt(&key, original_text)
+                        .expect("XOR key is empty or output is not valid UTF-8; use `try_encrypt` to handle this without panicking"),
+                Self::TransCipherPadded(key, pad_char) =>
+                    Self::trans_encrypt_padded(&key, pad_char, original_text)
             }
         }
-        /// Decrypts the data
+        /// Decrypts the data. Panics if this is a `TransCipherPadded` cipher
+        /// whose padding is invalid, or an `XorCipher` whose key is empty or
+        /// whose output isn't valid UTF-8; use `try_decrypt` to handle any
+        /// of these cases without panicking.
         pub fn decrypt(self, cipher_text: String) -> String {
             match self {
                 Self::ShiftCipher(key) =>
-                    Self::shift_decrypt(key % 26, cipher_text), 
-                Self::TransCipher(key) => 
-                    Self::trans_decrypt(key, cipher_text)
-            } 
+                    Self::shift_decrypt(key % 26, cipher_text),
+                Self::TransCipher(key) =>
+                    Self::trans_decrypt(&key, cipher_text),
+                Self::VigenereCipher(key) =>
+                    Self::vigenere_decrypt(&key, cipher_text),
+                Self::XorCipher(key) =>
+                    Self::xor_decrypt(&key, cipher_text)
+                        .expect("XOR key is empty or output is not valid UTF-8; use `try_decrypt` to handle this without panicking"),
+                Self::TransCipherPadded(key, pad_char) =>
+                    Self::trans_decrypt_padded(&key, pad_char, cipher_text)
+                        .expect("invalid padding; use `try_decrypt` to handle this without panicking")
+            }
         }
-
-        fn shift_encrypt(key: u8, mut original_text: String) -> String {
-            unsafe {
-                Self::rot_vec_up(key, original_text.as_mut_vec());
-                original_text
+        /// Encrypts the data, reporting an empty XOR key or non-UTF-8 XOR
+        /// output instead of panicking.
+        pub fn try_encrypt(self, original_text: String) -> Result<String, CipherError> {
+            match self {
+                Self::XorCipher(key) => Self::xor_encrypt(&key, original_text),
+                other => Ok(other.encrypt(original_text))
             }
         }
-        
-        fn shift_decrypt(key: u8, mut cipher_text: String) -> String {
-            unsafe {
-                Self::rot_vec_down(key, cipher_text.as_mut_vec());
-                cipher_text
+        /// Decrypts the data, reporting invalid padding, an empty XOR key,
+        /// or non-UTF-8 XOR output instead of panicking.
+        pub fn try_decrypt(self, cipher_text: String) -> Result<String, CipherError> {
+            match self {
+                Self::XorCipher(key) => Self::xor_decrypt(&key, cipher_text),
+                Self::TransCipherPadded(key, pad_char) =>
+                    Self::trans_decrypt_padded(&key, pad_char, cipher_text),
+                other => Ok(other.decrypt(cipher_text))
             }
         }
 
-        fn rot_vec_up(key: u8, org: &mut Vec<u8>) {
-            for c in org.iter_mut() {
-                if (b'A'..=b'Z').contains(c) || (b'a'..=b'z').contains(c) {
-                    *c = Self::rot_up(key, *c);
-                } else { 
-                    continue;
-                }
-            }
+        fn shift_encrypt(key: u8, original_text: String) -> String {
+            let mut chars: Vec<char> = original_text.chars().collect();
+            rot_vec_up(key, &mut chars);
+            chars.into_iter().collect()
         }
 
-        fn rot_vec_down(key: u8, org: &mut Vec<u8>) {
-            for c in org.iter_mut() {
-                if (b'A'..=b'Z').contains(c) || (b'a'..=b'z').contains(c) {
-                    *c = Self::rot_down(key, *c);
-                } else { 
-                    continue;
-                }
-            }
+        fn shift_decrypt(key: u8, cipher_text: String) -> String {
+            let mut chars: Vec<char> = cipher_text.chars().collect();
+            rot_vec_down(key, &mut chars);
+            chars.into_iter().collect()
         }
 
-        fn rot_up(key: u8, x: u8) -> u8 {
+        fn trans_encrypt(key: &[usize], original_text: String) -> String {
+            let mut chars: Vec<char> = original_text.chars().collect();
+            encrypt_trans_vec(key, &mut chars);
+            chars.into_iter().collect()
+        }
 
-            let rot_wrap_up = |b: u8| key - (b - x + 1);
-            match x {
-                x @ b'A'..=b'Z' if key + x > b'Z' => Self::rot_up(rot_wrap_up(b'Z'), b'A'), // For wrap around behaviour
-                x @ b'a'..=b'z' if key + x > b'z' => Self::rot_up(rot_wrap_up(b'z'), b'a'), 
-                _ => x + key
-            }
+        fn trans_decrypt(key: &[usize], original_text: String) -> String {
+            let mut chars: Vec<char> = original_text.chars().collect();
+            decrypt_trans_vec(key, &mut chars);
+            chars.into_iter().collect()
         }
 
-        fn rot_down(key: u8, x: u8) -> u8 {
-            // HELLO => WTAAD
-            let rot_wrap_down = |b: u8| key - (x - b + 1);
-            match x {
-                x @ b'A'..=b'Z' if x - key < b'A' => Self::rot_down(rot_wrap_down(b'A'), b'Z'), // For wrap around behaviour
-                x @ b'a'..=b'z' if x - key < b'a' => Self::rot_down(rot_wrap_down(b'a'), b'z'),
-                _ => x - key
-            }
+        /// Builds a Transpositional Cipher that pads the message to a
+        /// multiple of the key length before permuting, so messages of any
+        /// length round-trip correctly. Decrypt with `decrypt` (panics on
+        /// invalid padding) or `try_decrypt` (returns a `CipherError`).
+        pub fn trans_with_padding(key: Vec<usize>, pad_char: char) -> Self {
+            Self::TransCipherPadded(key, pad_char)
         }
 
-        fn trans_encrypt(key: Vec<usize>, mut original_text: String) -> String {
-            
-            unsafe {
-                Self::encrypt_trans_vec(key, original_text.as_mut_vec());
-                original_text
-            }
+        fn trans_encrypt_padded(key: &[usize], pad_char: char, original_text: String) -> String {
+            let mut chars: Vec<char> = original_text.chars().collect();
+            pkcs7_pad(&mut chars, key.len(), pad_char);
+            encrypt_trans_vec(key, &mut chars);
+            chars.into_iter().collect()
         }
 
-        fn trans_decrypt(key: Vec<usize>, mut original_text: String) -> String {
-            unsafe {
-                Self::decrypt_trans_vec(key, original_text.as_mut_vec());
-                original_text
-            }
+        fn trans_decrypt_padded(key: &[usize], pad_char: char, cipher_text: String) -> Result<String, CipherError> {
+            let mut chars: Vec<char> = cipher_text.chars().collect();
+            decrypt_trans_vec(key, &mut chars);
+            pkcs7_unpad(&mut chars, key.len(), pad_char)?;
+            Ok(chars.into_iter().collect())
         }
+
         /// Produce a key for Transpositional Cipher.
         pub fn trans_key_gen(key_size: usize) -> Vec<usize> {
-            
+
             let mut rng = thread_rng();
             let mut nrng = (&mut rng).sample_iter(Uniform::new_inclusive(0, key_size-1));
-            
+
             let mut v = Vec::with_capacity(key_size);
             let mut num: HashMap<usize, bool> = HashMap::new();
-            
+
             while v.len() != key_size {
                 let x = nrng.next().unwrap();
                 num.entry(x).or_insert_with(
                     || {
                         v.push(x);
                         true
-                    } 
+                    }
                 );
             }
-            
+
             v
         }
 
-        fn encrypt_trans_vec(key: Vec<usize>, org: &mut Vec<u8>) {
-            let mut i = 0;
-            let key_size = key.len();
-            while i + key_size < org.len() {
-            // print!("{} ", quo);
-                Self::encrypt_with_accord(&mut org[i..key_size + i], &key);
-                i += key_size;
-            }
+        fn vigenere_encrypt(key: &str, original_text: String) -> String {
+            let mut chars: Vec<char> = original_text.chars().collect();
+            rot_vec_up_keyed(key, &mut chars);
+            chars.into_iter().collect()
         }
 
-        fn decrypt_trans_vec(key: Vec<usize>, org: &mut Vec<u8>) {
-            let mut i = 0;
-            let key_size = key.len();
-            while i + key_size < org.len() {
-            // print!("{} ", quo);
-                Self::decrypt_with_accord(&mut org[i..key_size + i], &key);
-                i += key_size;
-            }
+        fn vigenere_decrypt(key: &str, cipher_text: String) -> String {
+            let mut chars: Vec<char> = cipher_text.chars().collect();
+            rot_vec_down_keyed(key, &mut chars);
+            chars.into_iter().collect()
         }
 
-        #[allow(clippy::ptr_arg)]
-        fn encrypt_with_accord<T: Copy>(v: &mut [T], acc: &Vec<usize>) {
-            let mut num: HashMap<usize, T> = HashMap::new();
-            for i in 0..v.len() {
-                num.insert(i, v[i]);
-                v[i] = match num.contains_key(&(acc[i])) {
-                    true    => num.remove(&(acc[i])).unwrap(),
-                    false   => v[acc[i]]
-                }
+        /// XOR operates on raw bytes rather than characters, so the result
+        /// can land on an invalid UTF-8 sequence; this reports that case
+        /// instead of corrupting the string. Also reports an empty key
+        /// instead of panicking, since `xor_vec` indexes into it.
+        fn xor_encrypt(key: &[u8], original_text: String) -> Result<String, CipherError> {
+            if key.is_empty() {
+                return Err(CipherError::EmptyKey);
             }
+            let mut bytes = original_text.into_bytes();
+            xor_vec(key, &mut bytes);
+            String::from_utf8(bytes).map_err(|_| CipherError::InvalidUtf8)
         }
-        #[allow(clippy::ptr_arg)]
-        fn decrypt_with_accord<T: Copy>(v: &mut [T], acc: &Vec<usize>) {
-        let mut num: HashMap<usize, T> = HashMap::new();
-        for i in 0..v.len() {
-            num.insert(acc[acc[i]] as usize, v[acc[i]]);
-            v[acc[i]] = match num.contains_key(&(acc[i])) {
-                true    => num.remove(&(acc[i])).unwrap(),
-                false   => v[i]
-            }
+
+        fn xor_decrypt(key: &[u8], cipher_text: String) -> Result<String, CipherError> {
+            // Repeating-key XOR is its own inverse.
+            Self::xor_encrypt(key, cipher_text)
+        }
+
+        /// Recovers the repeating-key XOR key from cipher-text bytes alone:
+        /// estimate the keysize from normalized Hamming distance between
+        /// blocks, then solve each of its columns as a single-byte XOR by
+        /// scoring candidate decryptions against English letter/space
+        /// frequency.
+        pub fn break_xor(cipher: &[u8]) -> Vec<u8> {
+            let key_size = estimate_xor_keysize(cipher);
+            (0..key_size)
+                .map(|col| {
+                    let column: Vec<u8> = cipher.iter()
+                        .skip(col)
+                        .step_by(key_size)
+                        .copied()
+                        .collect();
+                    best_single_byte_xor_key(&column)
+                })
+                .collect()
+        }
+
+        /// Recovers the Shift-Cipher key from cipher-text alone by picking
+        /// the shift whose decryption has the lowest chi-squared distance
+        /// from standard English letter frequencies.
+        pub fn break_shift(cipher_text: &str) -> u8 {
+            let letters: Vec<u8> = cipher_text.bytes()
+                .filter(u8::is_ascii_alphabetic)
+                .collect();
+            best_caesar_shift(&letters)
+        }
+
+        /// Recovers the keyword and plaintext of a Vigenère cipher-text
+        /// without knowing the key, returning `(key, plaintext)`.
+        pub fn break_vigenere(cipher_text: &str) -> (String, String) {
+            let letters: Vec<u8> = cipher_text.bytes()
+                .filter(u8::is_ascii_alphabetic)
+                .collect();
+            let key_len = estimate_vigenere_key_length(&letters);
+
+            let key_bytes: Vec<u8> = (0..key_len)
+                .map(|col| {
+                    let column: Vec<u8> = letters.iter()
+                        .skip(col)
+                        .step_by(key_len)
+                        .copied()
+                        .collect();
+                    b'A' + best_caesar_shift(&column)
+                })
+                .collect();
+            let key = String::from_utf8(key_bytes).unwrap();
+
+            let plaintext = Self::VigenereCipher(key.clone()).decrypt(cipher_text.to_string());
+            (key, plaintext)
         }
-    }
 
     }
 }
@@ -225,7 +789,7 @@ pub mod cipher {
 mod tests {
     #[test]
     fn shift_cipher_works() {
-        use crate::cipher::Ciphers; 
+        use crate::cipher::Ciphers;
         let key = 15;
         let message = String::from("HelloHowAreYou!");
         let manchine = Ciphers::ShiftCipher(key);
@@ -235,7 +799,7 @@ mod tests {
     }
     #[test]
     fn trans_cipher_works() {
-        use crate::cipher::Ciphers; 
+        use crate::cipher::Ciphers;
         let key_size = 5;
         let key = Ciphers::trans_key_gen(key_size);
         let manchine = Ciphers::TransCipher(key);
@@ -244,4 +808,176 @@ mod tests {
         let result = manchine.decrypt(cipher);
         assert_eq!(result, message);
     }
+    #[test]
+    fn trans_cipher_with_empty_key_is_a_no_op() {
+        use crate::cipher::Ciphers;
+        let message = String::from("HelloHowAreYou!");
+        let manchine = Ciphers::TransCipher(vec![]);
+        let cipher = manchine.clone().encrypt(message.clone());
+        assert_eq!(cipher, message);
+        let result = manchine.decrypt(cipher);
+        assert_eq!(result, message);
+    }
+    #[test]
+    fn trans_cipher_padded_with_empty_key_reports_invalid_padding() {
+        use crate::cipher::Ciphers;
+        let message = String::from("HelloHowAreYou!");
+        let manchine = Ciphers::trans_with_padding(vec![], '#');
+        let cipher = manchine.clone().encrypt(message);
+        assert!(manchine.try_decrypt(cipher).is_err());
+    }
+    #[test]
+    fn vigenere_cipher_works() {
+        use crate::cipher::Ciphers;
+        let key = String::from("lemon");
+        let message = String::from("HelloHowAreYou!");
+        let manchine = Ciphers::VigenereCipher(key);
+        let cipher = manchine.clone().encrypt(message.clone());
+        let result = manchine.decrypt(cipher);
+        assert_eq!(result, message);
+    }
+    #[test]
+    fn vigenere_cipher_with_empty_key_is_a_no_op() {
+        use crate::cipher::Ciphers;
+        let message = String::from("HelloHowAreYou!");
+        let manchine = Ciphers::VigenereCipher(String::new());
+        let cipher = manchine.clone().encrypt(message.clone());
+        assert_eq!(cipher, message);
+        let result = manchine.decrypt(cipher);
+        assert_eq!(result, message);
+    }
+    #[test]
+    fn break_vigenere_recovers_key_and_plaintext() {
+        use crate::cipher::Ciphers;
+        let key = String::from("lemon");
+        let message = String::from(
+            "AttackingtheeastsideofthecastleatdawnwillgiveusthebestchanceofsuccessMeetatthe\
+             oldbridgebeforesunriseandbringthedocumentswediscussedlastweek"
+        );
+        let manchine = Ciphers::VigenereCipher(key.clone());
+        let cipher = manchine.encrypt(message.clone());
+        let (found_key, plaintext) = Ciphers::break_vigenere(&cipher);
+        assert_eq!(found_key.to_lowercase(), key);
+        assert_eq!(plaintext, message);
+    }
+    #[test]
+    fn break_shift_recovers_key() {
+        use crate::cipher::Ciphers;
+        let key = 11;
+        let message = String::from(
+            "Thequickbrownfoxjumpsoverthelazydogandrunsintothedistance"
+        );
+        let manchine = Ciphers::ShiftCipher(key);
+        let cipher = manchine.encrypt(message);
+        let found_key = Ciphers::break_shift(&cipher);
+        assert_eq!(found_key, key);
+    }
+    #[test]
+    fn xor_cipher_works() {
+        use crate::cipher::Ciphers;
+        let key = vec![0x13, 0x37, 0x42];
+        let message = String::from("HelloHowAreYou!");
+        let manchine = Ciphers::XorCipher(key);
+        let cipher = manchine.clone().encrypt(message.clone());
+        let result = manchine.decrypt(cipher);
+        assert_eq!(result, message);
+    }
+    #[test]
+    fn break_xor_recovers_key() {
+        use crate::cipher::Ciphers;
+        let key = vec![b'k', b'e', b'y'];
+        let message = String::from(
+            "Attacking the east side of the castle at dawn will give us the best chance of success. \
+             Meet at the old bridge before sunrise and bring the documents we discussed last week. \
+             Make sure nobody from the village notices the extra guards posted near the gate tonight. \
+             Once the signal fires we move quickly and quietly toward the inner courtyard together."
+        );
+        let manchine = Ciphers::XorCipher(key.clone());
+        let cipher = manchine.encrypt(message.clone());
+        let found_key = Ciphers::break_xor(cipher.as_bytes());
+        assert_eq!(found_key, key);
+    }
+    #[test]
+    fn trans_cipher_padded_handles_arbitrary_length() {
+        use crate::cipher::Ciphers;
+        let key = Ciphers::trans_key_gen(4);
+        let message = String::from("HelloHowAreYou!"); // not a multiple of 4
+        let manchine = Ciphers::trans_with_padding(key, '#');
+        let cipher = manchine.clone().encrypt(message.clone());
+        assert_eq!(cipher.chars().count() % 4, 0);
+        let result = manchine.decrypt(cipher);
+        assert_eq!(result, message);
+    }
+    #[test]
+    fn trans_cipher_padded_rejects_corrupted_padding() {
+        use crate::cipher::Ciphers;
+        let key = Ciphers::trans_key_gen(4);
+        let manchine = Ciphers::trans_with_padding(key, '#');
+        // Not a multiple of the key length, so it can't be validly padded.
+        let garbage = String::from("abcde");
+        assert!(manchine.try_decrypt(garbage).is_err());
+    }
+    #[test]
+    fn trans_cipher_roundtrips_non_ascii_text() {
+        use crate::cipher::Ciphers;
+        let key_size = 4;
+        let key = Ciphers::trans_key_gen(key_size);
+        let manchine = Ciphers::TransCipher(key);
+        let message = String::from("café ñoño 日本語");
+        let cipher = manchine.clone().encrypt(message.clone());
+        let result = manchine.decrypt(cipher);
+        assert_eq!(result, message);
+    }
+    #[test]
+    fn xor_try_encrypt_reports_invalid_utf8() {
+        use crate::cipher::Ciphers;
+        // 0x41 ^ 0xFF = 0xBE, a standalone continuation byte: not valid UTF-8.
+        let manchine = Ciphers::XorCipher(vec![0xFF]);
+        let message = String::from("A");
+        assert!(manchine.try_encrypt(message).is_err());
+    }
+    #[test]
+    fn xor_try_encrypt_reports_empty_key() {
+        use crate::cipher::{Ciphers, CipherError};
+        let manchine = Ciphers::XorCipher(vec![]);
+        let message = String::from("Hello");
+        assert_eq!(manchine.try_encrypt(message), Err(CipherError::EmptyKey));
+    }
+    #[test]
+    fn cipher_trait_caesar_roundtrip() {
+        use crate::cipher::{Cipher, Caesar};
+        let caesar = Caesar::new(7);
+        let message = "HelloHowAreYou!";
+        let cipher = caesar.encrypt(message);
+        assert_eq!(caesar.decrypt(&cipher), message);
+    }
+    #[test]
+    fn cipher_trait_transposition_roundtrip() {
+        use crate::cipher::{Cipher, Ciphers, Transposition};
+        let key = Ciphers::trans_key_gen(5);
+        let trans = Transposition::new(key);
+        let message = "HelloHowAreYou!";
+        let cipher = trans.encrypt(message);
+        assert_eq!(trans.decrypt(&cipher), message);
+    }
+    #[test]
+    fn cipher_trait_vigenere_roundtrip() {
+        use crate::cipher::{Cipher, Vigenere};
+        let vigenere = Vigenere::new(String::from("lemon"));
+        let message = "HelloHowAreYou!";
+        let cipher = vigenere.encrypt(message);
+        assert_eq!(vigenere.decrypt(&cipher), message);
+    }
+    #[test]
+    #[should_panic]
+    fn caesar_rejects_out_of_range_key() {
+        use crate::cipher::{Cipher, Caesar};
+        Caesar::new(0);
+    }
+    #[test]
+    #[should_panic]
+    fn transposition_rejects_non_permutation_key() {
+        use crate::cipher::{Cipher, Transposition};
+        Transposition::new(vec![0, 0, 2]);
+    }
 }